@@ -10,6 +10,9 @@ pub enum ReaderError {
 
     #[error("Width Missmatch")]
     WidthMismatch(usize, usize),
+
+    #[error("Column boundary falls inside a UTF-8 codepoint")]
+    InvalidCharBoundary(usize),
 }
 
 impl From<std::io::Error> for ReaderError {
@@ -17,3 +20,21 @@ impl From<std::io::Error> for ReaderError {
         ReaderError::Io(value)
     }
 }
+
+#[derive(Debug, Error)]
+pub enum WriterError {
+    #[error("Io error: {0}")]
+    Io(std::io::Error),
+
+    #[error("Field count mismatch")]
+    FieldCountMismatch(usize, usize),
+
+    #[error("Field overflow")]
+    FieldOverflow(usize, usize, usize),
+}
+
+impl From<std::io::Error> for WriterError {
+    fn from(value: std::io::Error) -> Self {
+        WriterError::Io(value)
+    }
+}
@@ -0,0 +1,364 @@
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+
+use crate::reader::{Trim, WidthUnit};
+use crate::{ReaderBuilder, ReaderError, Record};
+
+impl ReaderBuilder {
+    /// Build an [`IndexedReader`] over `reader`.
+    pub fn build_indexed<R>(self, reader: R) -> IndexedReader<R>
+    where
+        R: Read + Seek,
+    {
+        IndexedReader::from_builder(self, reader)
+    }
+
+    /// Build an [`IndexedReader`] that reuses a persisted [`Index`].
+    pub fn build_indexed_with_index<R>(self, reader: R, index: Index) -> IndexedReader<R>
+    where
+        R: Read + Seek,
+    {
+        IndexedReader::from_builder_with_index(self, reader, index)
+    }
+}
+
+/// Byte-offset index of a fixed-width file: the header length and the start
+/// offset of each data record line. Persist and reload with [`Index::save`] /
+/// [`Index::load`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Index {
+    header_len: u64,
+    offsets: Vec<u64>,
+    complete: bool,
+}
+
+impl Index {
+    /// Byte length of the header line, including its line terminator.
+    pub fn header_len(&self) -> u64 {
+        self.header_len
+    }
+
+    /// Byte offsets of the indexed data record lines.
+    pub fn offsets(&self) -> &[u64] {
+        &self.offsets
+    }
+
+    /// Whether the scan that produced this index reached end of file.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Serialize the index in a compact little-endian binary form.
+    pub fn save<W>(&self, mut writer: W) -> std::io::Result<()>
+    where
+        W: Write,
+    {
+        writer.write_all(&self.header_len.to_le_bytes())?;
+        writer.write_all(&[self.complete as u8])?;
+        writer.write_all(&(self.offsets.len() as u64).to_le_bytes())?;
+        for offset in &self.offsets {
+            writer.write_all(&offset.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Load an index previously written by [`Index::save`].
+    pub fn load<R>(mut reader: R) -> std::io::Result<Self>
+    where
+        R: Read,
+    {
+        let mut word = [0u8; 8];
+        reader.read_exact(&mut word)?;
+        let header_len = u64::from_le_bytes(word);
+        let mut flag = [0u8; 1];
+        reader.read_exact(&mut flag)?;
+        let complete = flag[0] != 0;
+        reader.read_exact(&mut word)?;
+        let count = u64::from_le_bytes(word) as usize;
+        let mut offsets = Vec::with_capacity(count);
+        for _ in 0..count {
+            reader.read_exact(&mut word)?;
+            offsets.push(u64::from_le_bytes(word));
+        }
+        Ok(Self {
+            header_len,
+            offsets,
+            complete,
+        })
+    }
+}
+
+/// A seekable, indexed fixed-width reader that jumps directly to the nth
+/// record instead of streaming from the top. Construct one through
+/// [`ReaderBuilder::build_indexed`](crate::ReaderBuilder::build_indexed).
+#[derive(Debug)]
+pub struct IndexedReader<R> {
+    reader: R,
+    widths: Vec<usize>,
+    separator_length: usize,
+    flexible_width: bool,
+    width_unit: WidthUnit,
+    trim: Trim,
+    pad: char,
+    has_header: bool,
+    index: Index,
+    scan_pos: u64,
+    scanned_header: bool,
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read + Seek,
+{
+    pub(crate) fn from_builder(builder: ReaderBuilder, reader: R) -> Self {
+        Self {
+            reader,
+            widths: builder.widths,
+            separator_length: builder.separator_length,
+            flexible_width: builder.flexible_width,
+            width_unit: builder.width_unit,
+            trim: builder.trim,
+            pad: builder.pad,
+            has_header: builder.has_header,
+            index: Index::default(),
+            scan_pos: 0,
+            scanned_header: false,
+        }
+    }
+
+    pub(crate) fn from_builder_with_index(
+        builder: ReaderBuilder,
+        reader: R,
+        mut index: Index,
+    ) -> Self {
+        let scan_pos = if index.complete {
+            0
+        } else {
+            // Drop the last recorded offset so the scan re-reads that line and
+            // continues cleanly from a known boundary.
+            index.offsets.pop().unwrap_or(index.header_len)
+        };
+        Self {
+            reader,
+            widths: builder.widths,
+            separator_length: builder.separator_length,
+            flexible_width: builder.flexible_width,
+            width_unit: builder.width_unit,
+            trim: builder.trim,
+            pad: builder.pad,
+            has_header: builder.has_header,
+            index,
+            scan_pos,
+            scanned_header: true,
+        }
+    }
+
+    /// The index built so far.
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    /// Eagerly scan the whole file, building the complete index.
+    pub fn build_index(&mut self) -> Result<(), ReaderError> {
+        self.scan_upto(None)
+    }
+
+    /// Number of data records, scanning the whole file if not already complete.
+    pub fn len(&mut self) -> Result<usize, ReaderError> {
+        self.scan_upto(None)?;
+        Ok(self.index.offsets.len())
+    }
+
+    /// Whether the file has no data records.
+    pub fn is_empty(&mut self) -> Result<bool, ReaderError> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Parse the header record, if the file has one.
+    pub fn header(&mut self) -> Result<Option<Record>, ReaderError> {
+        self.scan_header()?;
+        if !self.has_header {
+            return Ok(None);
+        }
+        let line = self.read_line_at(0)?;
+        self.parse(line).map(Some)
+    }
+
+    /// Fetch the nth data record (zero-based), or `None` if out of range.
+    pub fn record(&mut self, n: usize) -> Result<Option<Record>, ReaderError> {
+        self.scan_upto(Some(n))?;
+        match self.index.offsets.get(n).copied() {
+            None => Ok(None),
+            Some(offset) => {
+                let line = self.read_line_at(offset)?;
+                self.parse(line).map(Some)
+            }
+        }
+    }
+
+    /// Fetch a contiguous range of data records, stopping at end of file.
+    pub fn fetch(&mut self, range: std::ops::Range<usize>) -> Result<Vec<Record>, ReaderError> {
+        if let Some(last) = range.end.checked_sub(1) {
+            self.scan_upto(Some(last))?;
+        }
+        let mut records = Vec::new();
+        for n in range {
+            match self.record(n)? {
+                Some(record) => records.push(record),
+                None => break,
+            }
+        }
+        Ok(records)
+    }
+
+    fn parse(&self, line: String) -> Result<Record, ReaderError> {
+        Record::try_new(
+            line,
+            &self.widths,
+            self.separator_length,
+            self.flexible_width,
+            self.width_unit,
+            self.trim,
+            self.pad,
+        )
+    }
+
+    fn scan_header(&mut self) -> Result<(), ReaderError> {
+        if self.scanned_header {
+            return Ok(());
+        }
+        if self.has_header {
+            self.reader.seek(SeekFrom::Start(0))?;
+            let mut buf = Vec::new();
+            let n = BufReader::new(&mut self.reader).read_until(b'\n', &mut buf)?;
+            self.index.header_len = n as u64;
+            self.scan_pos = n as u64;
+        } else {
+            self.index.header_len = 0;
+            self.scan_pos = 0;
+        }
+        self.scanned_header = true;
+        Ok(())
+    }
+
+    /// Scan forward, recording line offsets, until the given record index is
+    /// covered or EOF is reached. `None` scans the whole file.
+    fn scan_upto(&mut self, target: Option<usize>) -> Result<(), ReaderError> {
+        self.scan_header()?;
+        if self.index.complete {
+            return Ok(());
+        }
+        self.reader.seek(SeekFrom::Start(self.scan_pos))?;
+        let mut buf = BufReader::new(&mut self.reader);
+        let mut pos = self.scan_pos;
+        loop {
+            if let Some(target) = target {
+                if self.index.offsets.len() > target {
+                    break;
+                }
+            }
+            let mut line = Vec::new();
+            let n = buf.read_until(b'\n', &mut line)?;
+            if n == 0 {
+                self.index.complete = true;
+                break;
+            }
+            self.index.offsets.push(pos);
+            pos += n as u64;
+        }
+        self.scan_pos = pos;
+        Ok(())
+    }
+
+    fn read_line_at(&mut self, offset: u64) -> Result<String, ReaderError> {
+        self.reader.seek(SeekFrom::Start(offset))?;
+        let mut buf = Vec::new();
+        BufReader::new(&mut self.reader).read_until(b'\n', &mut buf)?;
+        if buf.last() == Some(&b'\n') {
+            buf.pop();
+            if buf.last() == Some(&b'\r') {
+                buf.pop();
+            }
+        }
+        String::from_utf8(buf)
+            .map_err(|e| ReaderError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn sample() -> Cursor<&'static [u8]> {
+        Cursor::new(b"id header\n001alice \n002bob   \n003carol \n".as_slice())
+    }
+
+    fn reader(cur: Cursor<&'static [u8]>) -> IndexedReader<Cursor<&'static [u8]>> {
+        ReaderBuilder::new(vec![3, 6]).has_header(true).build_indexed(cur)
+    }
+
+    #[test]
+    fn test_indexed_header() {
+        let mut reader = reader(sample());
+        let header = reader.header().unwrap().unwrap();
+        assert_eq!(header.get(0), Some("id "));
+        assert_eq!(header.get(1), Some("header"));
+    }
+
+    #[test]
+    fn test_indexed_random_access() {
+        let mut reader = reader(sample());
+        let record = reader.record(2).unwrap().unwrap();
+        assert_eq!(record.get(0), Some("003"));
+        assert_eq!(record.get(1), Some("carol "));
+
+        let first = reader.record(0).unwrap().unwrap();
+        assert_eq!(first.get(0), Some("001"));
+
+        assert!(reader.record(3).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_indexed_lazy_scan_stops_early() {
+        let mut reader = reader(sample());
+        reader.record(0).unwrap().unwrap();
+        // Only enough of the file was scanned to resolve record 0.
+        assert!(!reader.index().is_complete());
+        assert_eq!(reader.index().offsets().len(), 1);
+    }
+
+    #[test]
+    fn test_indexed_len_and_fetch() {
+        let mut reader = reader(sample());
+        assert_eq!(reader.len().unwrap(), 3);
+
+        let slice = reader.fetch(1..3).unwrap();
+        assert_eq!(slice.len(), 2);
+        assert_eq!(slice[0].get(0), Some("002"));
+        assert_eq!(slice[1].get(0), Some("003"));
+    }
+
+    #[test]
+    fn test_index_save_and_load_round_trip() {
+        let mut reader = reader(sample());
+        reader.build_index().unwrap();
+        let mut bytes = Vec::new();
+        reader.index().save(&mut bytes).unwrap();
+
+        let loaded = Index::load(Cursor::new(bytes)).unwrap();
+        assert_eq!(&loaded, reader.index());
+    }
+
+    #[test]
+    fn test_indexed_reuses_loaded_index() {
+        let mut first = reader(sample());
+        first.build_index().unwrap();
+        let index = first.index().clone();
+
+        let mut reused = ReaderBuilder::new(vec![3, 6])
+            .has_header(true)
+            .build_indexed_with_index(sample(), index);
+        let record = reused.record(1).unwrap().unwrap();
+        assert_eq!(record.get(0), Some("002"));
+    }
+}
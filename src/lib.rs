@@ -1,6 +1,10 @@
 mod error;
+mod indexed;
 mod reader;
+mod writer;
 
-pub use error::ReaderError;
+pub use error::{ReaderError, WriterError};
 
-pub use reader::{Record, RecordIter, FwrFieldIter, Reader};
+pub use indexed::{Index, IndexedReader};
+pub use reader::{FwrFieldIter, Reader, ReaderBuilder, Record, RecordIter, Trim, WidthUnit};
+pub use writer::{Alignment, Overflow, Writer, WriterBuilder};
@@ -5,12 +5,144 @@ use std::{
 
 use crate::ReaderError;
 
+/// Unit in which `widths` and `separator_length` are measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WidthUnit {
+    Bytes,
+    Chars,
+}
+
+/// Which padding to strip from a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trim {
+    None,
+    Leading,
+    Trailing,
+    Both,
+}
+
+fn trim_range(line: &str, range: Range<usize>, trim: Trim, pad: char) -> Range<usize> {
+    let Range { mut start, mut end } = range;
+    if matches!(trim, Trim::Leading | Trim::Both) {
+        for c in line[start..end].chars() {
+            if c == pad {
+                start += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+    }
+    if matches!(trim, Trim::Trailing | Trim::Both) {
+        for c in line[start..end].chars().rev() {
+            if c == pad {
+                end -= c.len_utf8();
+            } else {
+                break;
+            }
+        }
+    }
+    start..end
+}
+
+/// Builder for [`Reader`] and [`IndexedReader`](crate::IndexedReader).
+#[derive(Debug, Clone)]
+pub struct ReaderBuilder {
+    pub(crate) widths: Vec<usize>,
+    pub(crate) separator_length: usize,
+    pub(crate) flexible_width: bool,
+    pub(crate) width_unit: WidthUnit,
+    pub(crate) trim: Trim,
+    pub(crate) pad: char,
+    pub(crate) spans: Option<Vec<Range<usize>>>,
+    pub(crate) has_header: bool,
+}
+
+impl ReaderBuilder {
+    /// Start a builder with consecutive column `widths`.
+    pub fn new(widths: Vec<usize>) -> Self {
+        Self {
+            widths,
+            separator_length: 0,
+            flexible_width: false,
+            width_unit: WidthUnit::Chars,
+            trim: Trim::None,
+            pad: ' ',
+            spans: None,
+            has_header: false,
+        }
+    }
+
+    /// Start a builder from explicit column spans. Spans may project a subset
+    /// of columns, skip gaps, or overlap.
+    pub fn from_spans(spans: Vec<Range<usize>>) -> Self {
+        Self {
+            spans: Some(spans),
+            ..Self::new(Vec::new())
+        }
+    }
+
+    pub fn separator_length(mut self, separator_length: usize) -> Self {
+        self.separator_length = separator_length;
+        self
+    }
+
+    pub fn flexible_width(mut self, flexible_width: bool) -> Self {
+        self.flexible_width = flexible_width;
+        self
+    }
+
+    pub fn width_unit(mut self, width_unit: WidthUnit) -> Self {
+        self.width_unit = width_unit;
+        self
+    }
+
+    pub fn trim(mut self, trim: Trim) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    pub fn pad(mut self, pad: char) -> Self {
+        self.pad = pad;
+        self
+    }
+
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Build a streaming [`Reader`] over `reader`.
+    pub fn build<R>(self, reader: R) -> Result<Reader<R>, ReaderError>
+    where
+        R: Read,
+    {
+        let lines = BufReader::new(reader).lines();
+        let mut this = Reader {
+            lines,
+            widths: self.widths,
+            separator_length: self.separator_length,
+            flexible_width: self.flexible_width,
+            width_unit: self.width_unit,
+            trim: self.trim,
+            pad: self.pad,
+            spans: self.spans,
+            header: None,
+        };
+        this.read_header(self.has_header)?;
+        Ok(this)
+    }
+}
+
 #[derive(Debug)]
 pub struct Reader<R> {
     lines: Lines<BufReader<R>>,
     widths: Vec<usize>,
     separator_length: usize,
     flexible_width: bool,
+    width_unit: WidthUnit,
+    trim: Trim,
+    pad: char,
+    spans: Option<Vec<Range<usize>>>,
     header: Option<Record>,
 }
 
@@ -18,34 +150,34 @@ impl<R> Reader<R>
 where
     R: Read,
 {
-    pub fn new(
-        reader: R,
-        widths: Vec<usize>,
-        separator_length: usize,
-        flexible_width: bool,
-        has_header: bool,
-    ) -> Result<Self, ReaderError> {
-        let mut lines = BufReader::new(reader).lines();
-        let header = {
-            if has_header {
-                let line = lines.next().ok_or(ReaderError::EmptyLine)??;
-                Some(Record::try_new(
-                    line,
-                    &widths,
-                    separator_length,
-                    flexible_width,
-                )?)
-            } else {
-                None
-            }
-        };
-        Ok(Self {
-            lines,
-            widths,
-            separator_length,
-            flexible_width,
-            header,
-        })
+    /// Create a reader over `reader` with consecutive column `widths` and
+    /// default settings. Use [`ReaderBuilder`] to configure separators,
+    /// trimming, spans, or a header.
+    pub fn new(reader: R, widths: Vec<usize>) -> Result<Self, ReaderError> {
+        ReaderBuilder::new(widths).build(reader)
+    }
+
+    fn read_header(&mut self, has_header: bool) -> Result<(), ReaderError> {
+        if has_header {
+            let line = self.lines.next().ok_or(ReaderError::EmptyLine)??;
+            self.header = Some(self.parse_line(line)?);
+        }
+        Ok(())
+    }
+
+    fn parse_line(&self, line: String) -> Result<Record, ReaderError> {
+        match &self.spans {
+            Some(spans) => Record::from_spans(line, spans, self.trim, self.pad),
+            None => Record::try_new(
+                line,
+                &self.widths,
+                self.separator_length,
+                self.flexible_width,
+                self.width_unit,
+                self.trim,
+                self.pad,
+            ),
+        }
     }
 
     pub fn header(&self) -> Option<Record> {
@@ -75,14 +207,10 @@ where
     type Item = Result<Record, ReaderError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.reader.lines.next().map(|result| {
-            Record::try_new(
-                result?,
-                &self.reader.widths,
-                self.reader.separator_length,
-                self.reader.flexible_width,
-            )
-        })
+        self.reader
+            .lines
+            .next()
+            .map(|result| self.reader.parse_line(result?))
     }
 }
 
@@ -98,49 +226,148 @@ impl Record {
         widths: &[usize],
         sep_len: usize,
         flexible_widths: bool,
+        width_unit: WidthUnit,
+        trim: Trim,
+        pad: char,
     ) -> Result<Self, ReaderError> {
         if line.is_empty() {
             Err(ReaderError::EmptyLine)
         } else {
             let mut start = 0;
+            let last_index = widths.len().saturating_sub(1);
             let ranges = widths
                 .iter()
                 .copied()
-                .map(|w| {
-                    let rem = line.len() - start;
-                    match rem.cmp(&w) {
-                        std::cmp::Ordering::Less => {
-                            if flexible_widths {
+                .enumerate()
+                .map(|(index, w)| {
+                    // In flexible mode the final field absorbs the rest of the
+                    // line verbatim, however long it is.
+                    if flexible_widths && index == last_index {
+                        let rng = start..line.len();
+                        start = line.len();
+                        return Ok(rng);
+                    }
+                    match width_unit {
+                    WidthUnit::Bytes => {
+                        let rem = line.len() - start;
+                        match rem.cmp(&w) {
+                            std::cmp::Ordering::Less => {
+                                if flexible_widths {
+                                    let rng = start..line.len();
+                                    start = line.len();
+                                    Ok(rng)
+                                } else {
+                                    let err = ReaderError::WidthMismatch(start, w);
+                                    start = line.len();
+                                    Err(err)
+                                }
+                            }
+                            std::cmp::Ordering::Equal => {
                                 let rng = start..line.len();
                                 start = line.len();
                                 Ok(rng)
-                            } else {
-                                let err = ReaderError::WidthMismatch(start, w);
-                                start = line.len();
-                                Err(err)
+                            }
+                            std::cmp::Ordering::Greater => {
+                                let end = start + w;
+                                if !line.is_char_boundary(end) {
+                                    return Err(ReaderError::InvalidCharBoundary(end));
+                                }
+                                let rng = start..end;
+                                start = (end + sep_len).min(line.len());
+                                if !line.is_char_boundary(start) {
+                                    return Err(ReaderError::InvalidCharBoundary(start));
+                                }
+                                Ok(rng)
                             }
                         }
-                        std::cmp::Ordering::Equal => {
-                            let rng = start..line.len();
-                            start = line.len();
-                            Ok(rng)
+                    }
+                    WidthUnit::Chars => {
+                        let rem = line[start..].chars().count();
+                        match rem.cmp(&w) {
+                            std::cmp::Ordering::Less => {
+                                if flexible_widths {
+                                    let rng = start..line.len();
+                                    start = line.len();
+                                    Ok(rng)
+                                } else {
+                                    let err = ReaderError::WidthMismatch(start, w);
+                                    start = line.len();
+                                    Err(err)
+                                }
+                            }
+                            std::cmp::Ordering::Equal => {
+                                let rng = start..line.len();
+                                start = line.len();
+                                Ok(rng)
+                            }
+                            std::cmp::Ordering::Greater => line[start..]
+                                .char_indices()
+                                .nth(w)
+                                .map(|(i, _)| {
+                                    let end = start + i;
+                                    let rng = start..end;
+                                    let sep_bytes = line[end..]
+                                        .char_indices()
+                                        .nth(sep_len)
+                                        .map(|(i, _)| i)
+                                        .unwrap_or(line.len() - end);
+                                    start = end + sep_bytes;
+                                    rng
+                                })
+                                .ok_or(ReaderError::WidthMismatch(start, w)),
                         }
-                        std::cmp::Ordering::Greater => line[start..]
-                            .char_indices()
-                            .nth(w)
-                            .map(|(i, _)| {
-                                let end = start + i;
-                                let rng = start..end;
-                                start = end + sep_len;
-                                rng
-                            })
-                            .ok_or(ReaderError::WidthMismatch(start, w)),
+                    }
                     }
                 })
                 .collect::<Result<Vec<_>, ReaderError>>()?;
+            let ranges = ranges
+                .into_iter()
+                .map(|range| trim_range(&line, range, trim, pad))
+                .collect();
             Ok(Self { line, ranges })
         }
     }
+    /// Build a record from explicit spans into `line`, in the order given.
+    pub fn from_spans(
+        line: String,
+        spans: &[Range<usize>],
+        trim: Trim,
+        pad: char,
+    ) -> Result<Self, ReaderError> {
+        if line.is_empty() {
+            return Err(ReaderError::EmptyLine);
+        }
+        for span in spans {
+            if span.end > line.len() {
+                return Err(ReaderError::WidthMismatch(span.start, span.end));
+            }
+            if !line.is_char_boundary(span.start) {
+                return Err(ReaderError::InvalidCharBoundary(span.start));
+            }
+            if !line.is_char_boundary(span.end) {
+                return Err(ReaderError::InvalidCharBoundary(span.end));
+            }
+        }
+        let ranges = spans
+            .iter()
+            .map(|span| trim_range(&line, span.clone(), trim, pad))
+            .collect();
+        Ok(Self { line, ranges })
+    }
+
+    /// Derive cumulative spans from `widths` and a uniform `separator_length`.
+    pub fn spans_from_widths(widths: &[usize], separator_length: usize) -> Vec<Range<usize>> {
+        let mut start = 0;
+        widths
+            .iter()
+            .map(|&width| {
+                let range = start..start + width;
+                start += width + separator_length;
+                range
+            })
+            .collect()
+    }
+
     pub fn get(&self, index: usize) -> Option<&str> {
         self.ranges
             .get(index)
@@ -208,14 +435,10 @@ mod tests {
         let file_path = create_test_file(content).unwrap();
         let widths = vec![7, 7, 7];
 
-        let reader = Reader::new(
-            File::open(file_path.clone()).unwrap(),
-            widths,
-            0,
-            false,
-            true,
-        )
-        .unwrap();
+        let reader = ReaderBuilder::new(widths)
+            .has_header(true)
+            .build(File::open(file_path.clone()).unwrap())
+            .unwrap();
 
         let header = reader.header().clone().unwrap();
         assert_eq!(header.get(0), Some("header1"));
@@ -244,14 +467,9 @@ mod tests {
         let file_path = create_test_file(content).unwrap();
         let widths = vec![3, 3, 3];
 
-        let reader = Reader::new(
-            File::open(file_path.clone()).unwrap(),
-            widths,
-            0,
-            false,
-            false,
-        )
-        .unwrap();
+        let reader = ReaderBuilder::new(widths)
+            .build(File::open(file_path.clone()).unwrap())
+            .unwrap();
 
         let header = reader.header();
         assert!(header.is_none());
@@ -278,14 +496,10 @@ mod tests {
         let file_path = create_test_file(content).unwrap();
         let widths = vec![3, 3, 3];
 
-        let reader = Reader::new(
-            File::open(file_path.clone()).unwrap(),
-            widths,
-            1,
-            false,
-            false,
-        )
-        .unwrap();
+        let reader = ReaderBuilder::new(widths)
+            .separator_length(1)
+            .build(File::open(file_path.clone()).unwrap())
+            .unwrap();
 
         let mut records = reader.records();
         let record1 = records.next().unwrap().unwrap();
@@ -309,14 +523,10 @@ mod tests {
         let file_path = create_test_file(content).unwrap();
         let widths = vec![3, 3, 3];
 
-        let reader = Reader::new(
-            File::open(file_path.clone()).unwrap(),
-            widths,
-            0,
-            true,
-            false,
-        )
-        .unwrap();
+        let reader = ReaderBuilder::new(widths)
+            .flexible_width(true)
+            .build(File::open(file_path.clone()).unwrap())
+            .unwrap();
 
         let mut records = reader.records();
         let record1 = records.next().unwrap().unwrap();
@@ -340,14 +550,9 @@ mod tests {
         let file_path = create_test_file(content).unwrap();
         let widths = vec![3, 3, 3];
 
-        let reader = Reader::new(
-            File::open(file_path.clone()).unwrap(),
-            widths,
-            0,
-            false,
-            false,
-        )
-        .unwrap();
+        let reader = ReaderBuilder::new(widths)
+            .build(File::open(file_path.clone()).unwrap())
+            .unwrap();
 
         let header = reader.header();
         assert!(header.is_none());
@@ -372,6 +577,10 @@ mod tests {
                 widths,
                 separator_length,
                 flexible_width,
+                width_unit: WidthUnit::Chars,
+                trim: Trim::None,
+                pad: ' ',
+                spans: None,
                 header: None,
             },
         };
@@ -401,6 +610,10 @@ mod tests {
                 widths,
                 separator_length,
                 flexible_width,
+                width_unit: WidthUnit::Chars,
+                trim: Trim::None,
+                pad: ' ',
+                spans: None,
                 header: None,
             },
         };
@@ -430,6 +643,10 @@ mod tests {
                 widths,
                 separator_length,
                 flexible_width,
+                width_unit: WidthUnit::Chars,
+                trim: Trim::None,
+                pad: ' ',
+                spans: None,
                 header: None,
             },
         };
@@ -459,6 +676,10 @@ mod tests {
                 widths,
                 separator_length,
                 flexible_width,
+                width_unit: WidthUnit::Chars,
+                trim: Trim::None,
+                pad: ' ',
+                spans: None,
                 header: None,
             },
         };
@@ -492,6 +713,10 @@ mod tests {
                 widths,
                 separator_length,
                 flexible_width,
+                width_unit: WidthUnit::Chars,
+                trim: Trim::None,
+                pad: ' ',
+                spans: None,
                 header: None,
             },
         };
@@ -524,6 +749,10 @@ mod tests {
                 widths,
                 separator_length,
                 flexible_width,
+                width_unit: WidthUnit::Chars,
+                trim: Trim::None,
+                pad: ' ',
+                spans: None,
                 header: None,
             },
         };
@@ -542,7 +771,7 @@ mod tests {
         let sep_len = 0;
         let flexible_widths = false;
 
-        let record = Record::try_new(line.clone(), &widths, sep_len, flexible_widths);
+        let record = Record::try_new(line.clone(), &widths, sep_len, flexible_widths, WidthUnit::Chars, Trim::None, ' ');
 
         assert!(record.is_ok());
         let record = record.unwrap();
@@ -557,7 +786,7 @@ mod tests {
         let sep_len = 0;
         let flexible_widths = true;
 
-        let record = Record::try_new(line.clone(), &widths, sep_len, flexible_widths);
+        let record = Record::try_new(line.clone(), &widths, sep_len, flexible_widths, WidthUnit::Chars, Trim::None, ' ');
 
         assert!(record.is_ok());
         let record = record.unwrap();
@@ -572,7 +801,7 @@ mod tests {
         let sep_len = 0;
         let flexible_widths = false;
 
-        let record = Record::try_new(line, &widths, sep_len, flexible_widths);
+        let record = Record::try_new(line, &widths, sep_len, flexible_widths, WidthUnit::Chars, Trim::None, ' ');
 
         assert!(record.is_err());
         let err = record.unwrap_err();
@@ -586,7 +815,7 @@ mod tests {
         let sep_len = 1;
         let flexible_widths = false;
 
-        let record = Record::try_new(line.clone(), &widths, sep_len, flexible_widths);
+        let record = Record::try_new(line.clone(), &widths, sep_len, flexible_widths, WidthUnit::Chars, Trim::None, ' ');
 
         assert!(record.is_ok());
         let record = record.unwrap();
@@ -601,7 +830,7 @@ mod tests {
         let sep_len = 0;
         let flexible_widths = false;
 
-        let record = Record::try_new(line.clone(), &widths, sep_len, flexible_widths).unwrap();
+        let record = Record::try_new(line.clone(), &widths, sep_len, flexible_widths, WidthUnit::Chars, Trim::None, ' ').unwrap();
 
         assert_eq!(record.get(0), Some("123"));
         assert_eq!(record.get(1), Some("456"));
@@ -616,7 +845,7 @@ mod tests {
         let sep_len = 0;
         let flexible_widths = false;
 
-        let record = Record::try_new(line.clone(), &widths, sep_len, flexible_widths).unwrap();
+        let record = Record::try_new(line.clone(), &widths, sep_len, flexible_widths, WidthUnit::Chars, Trim::None, ' ').unwrap();
         let fields: Vec<&str> = record.iter().collect();
 
         assert_eq!(fields, vec!["123", "456", "789"]);
@@ -629,10 +858,154 @@ mod tests {
         let sep_len = 0;
         let flexible_widths = false;
 
-        let record = Record::try_new(line, &widths, sep_len, flexible_widths);
+        let record = Record::try_new(line, &widths, sep_len, flexible_widths, WidthUnit::Chars, Trim::None, ' ');
 
         assert!(record.is_err());
         let err = record.unwrap_err();
         assert!(matches!(err, ReaderError::EmptyLine));
     }
+
+    #[test]
+    fn test_char_unit_counts_scalar_values() {
+        // Two two-byte characters per column.
+        let line = "ααββγγ".to_string();
+        let record = Record::try_new(line.clone(), &[2, 2, 2], 0, false, WidthUnit::Chars, Trim::None, ' ').unwrap();
+
+        assert_eq!(record.get(0), Some("αα"));
+        assert_eq!(record.get(1), Some("ββ"));
+        assert_eq!(record.get(2), Some("γγ"));
+    }
+
+    #[test]
+    fn test_byte_unit_slices_on_byte_offsets() {
+        // Each "α" is two bytes, so a four-byte column holds two of them.
+        let line = "ααββ".to_string();
+        let record = Record::try_new(line.clone(), &[4, 4], 0, false, WidthUnit::Bytes, Trim::None, ' ').unwrap();
+
+        assert_eq!(record.get(0), Some("αα"));
+        assert_eq!(record.get(1), Some("ββ"));
+    }
+
+    #[test]
+    fn test_byte_unit_rejects_mid_codepoint_boundary() {
+        // A three-byte column cuts the second "α" in half.
+        let line = "αα".to_string();
+        let record = Record::try_new(line, &[3, 1], 0, false, WidthUnit::Bytes, Trim::None, ' ');
+
+        assert!(matches!(
+            record.unwrap_err(),
+            ReaderError::InvalidCharBoundary(3)
+        ));
+    }
+
+    #[test]
+    fn test_from_spans_projects_subset_and_gaps() {
+        // Skip a two-character filler gap and project only two columns.
+        let line = "abc##de".to_string();
+        let record = Record::from_spans(line, &[0..3, 5..7], Trim::None, ' ').unwrap();
+
+        assert_eq!(record.get(0), Some("abc"));
+        assert_eq!(record.get(1), Some("de"));
+        assert_eq!(record.get(2), None);
+    }
+
+    #[test]
+    fn test_from_spans_allows_overlap() {
+        let line = "123456".to_string();
+        let record = Record::from_spans(line, &[0..4, 2..6], Trim::None, ' ').unwrap();
+
+        assert_eq!(record.get(0), Some("1234"));
+        assert_eq!(record.get(1), Some("3456"));
+    }
+
+    #[test]
+    fn test_from_spans_out_of_range() {
+        let line = "123".to_string();
+        let record = Record::from_spans(line, &[(0..5)], Trim::None, ' ');
+
+        assert!(matches!(
+            record.unwrap_err(),
+            ReaderError::WidthMismatch(0, 5)
+        ));
+    }
+
+    #[test]
+    fn test_spans_from_widths_matches_width_layout() {
+        assert_eq!(
+            Record::spans_from_widths(&[3, 3, 3], 1),
+            vec![0..3, 4..7, 8..11]
+        );
+    }
+
+    #[test]
+    fn test_reader_with_spans() {
+        let data = "abc##de\nfgh##ij\n".as_bytes();
+        let reader = ReaderBuilder::from_spans(vec![0..3, 5..7])
+            .build(Cursor::new(data))
+            .unwrap();
+
+        let mut records = reader.records();
+        let first = records.next().unwrap().unwrap();
+        assert_eq!(first.get(0), Some("abc"));
+        assert_eq!(first.get(1), Some("de"));
+
+        let second = records.next().unwrap().unwrap();
+        assert_eq!(second.get(0), Some("fgh"));
+        assert_eq!(second.get(1), Some("ij"));
+
+        assert!(records.next().is_none());
+    }
+
+    #[test]
+    fn test_trim_trailing_spaces() {
+        let line = "987    654    321    ".to_string();
+        let record =
+            Record::try_new(line, &[7, 7, 7], 0, false, WidthUnit::Chars, Trim::Trailing, ' ')
+                .unwrap();
+
+        assert_eq!(record.get(0), Some("987"));
+        assert_eq!(record.get(1), Some("654"));
+        assert_eq!(record.get(2), Some("321"));
+    }
+
+    #[test]
+    fn test_trim_leading_zeros() {
+        let line = "007042".to_string();
+        let record =
+            Record::try_new(line, &[3, 3], 0, false, WidthUnit::Chars, Trim::Leading, '0').unwrap();
+
+        assert_eq!(record.get(0), Some("7"));
+        assert_eq!(record.get(1), Some("42"));
+    }
+
+    #[test]
+    fn test_trim_both_keeps_inner_pad() {
+        let line = "  a b  ".to_string();
+        let record =
+            Record::try_new(line, &[7], 0, false, WidthUnit::Chars, Trim::Both, ' ').unwrap();
+
+        assert_eq!(record.get(0), Some("a b"));
+    }
+
+    #[test]
+    fn test_trim_all_pad_yields_empty() {
+        let line = "   ".to_string();
+        let record =
+            Record::try_new(line, &[3], 0, false, WidthUnit::Chars, Trim::Both, ' ').unwrap();
+
+        assert_eq!(record.get(0), Some(""));
+    }
+
+    #[test]
+    fn test_reader_trims_fields() {
+        let data = "987    654    321    \n".as_bytes();
+        let reader = ReaderBuilder::new(vec![7, 7, 7])
+            .trim(Trim::Trailing)
+            .build(Cursor::new(data))
+            .unwrap();
+
+        let record = reader.records().next().unwrap().unwrap();
+        assert_eq!(record.get(0), Some("987"));
+        assert_eq!(record.get(2), Some("321"));
+    }
 }
@@ -0,0 +1,320 @@
+use std::io::Write;
+
+use crate::error::WriterError;
+
+/// Alignment of a field within its fixed-width column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Right,
+}
+
+/// Behavior applied when a field is wider than its column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Cut the field down to the column width.
+    Truncate,
+    /// Reject the field with [`WriterError::FieldOverflow`].
+    Error,
+}
+
+/// Builder for [`Writer`], mirroring the column layout accepted by
+/// [`Reader`](crate::Reader) so that a `Reader` → `Writer` round-trip
+/// reproduces the original fixed-width layout.
+#[derive(Debug, Clone)]
+pub struct WriterBuilder {
+    widths: Vec<usize>,
+    separator_length: usize,
+    flexible_width: bool,
+    alignments: Vec<Alignment>,
+    pad: char,
+    separator: char,
+    overflow: Overflow,
+    header: Option<Vec<String>>,
+}
+
+impl WriterBuilder {
+    pub fn new(widths: Vec<usize>) -> Self {
+        Self {
+            widths,
+            separator_length: 0,
+            flexible_width: false,
+            alignments: Vec::new(),
+            pad: ' ',
+            separator: ' ',
+            overflow: Overflow::Error,
+            header: None,
+        }
+    }
+
+    pub fn separator_length(mut self, separator_length: usize) -> Self {
+        self.separator_length = separator_length;
+        self
+    }
+
+    pub fn flexible_width(mut self, flexible_width: bool) -> Self {
+        self.flexible_width = flexible_width;
+        self
+    }
+
+    pub fn alignments(mut self, alignments: Vec<Alignment>) -> Self {
+        self.alignments = alignments;
+        self
+    }
+
+    pub fn pad(mut self, pad: char) -> Self {
+        self.pad = pad;
+        self
+    }
+
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn header(mut self, header: Vec<String>) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    pub fn build<W>(self, writer: W) -> Result<Writer<W>, WriterError>
+    where
+        W: Write,
+    {
+        let mut writer = Writer {
+            writer,
+            widths: self.widths,
+            separator_length: self.separator_length,
+            flexible_width: self.flexible_width,
+            alignments: self.alignments,
+            pad: self.pad,
+            separator: self.separator,
+            overflow: self.overflow,
+        };
+        if let Some(header) = self.header {
+            writer.write_record(header.iter().map(String::as_str))?;
+        }
+        Ok(writer)
+    }
+}
+
+/// Serializes fields back into fixed-width lines.
+#[derive(Debug)]
+pub struct Writer<W> {
+    writer: W,
+    widths: Vec<usize>,
+    separator_length: usize,
+    flexible_width: bool,
+    alignments: Vec<Alignment>,
+    pad: char,
+    separator: char,
+    overflow: Overflow,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    pub fn new(writer: W, widths: Vec<usize>) -> Self {
+        Self {
+            writer,
+            widths,
+            separator_length: 0,
+            flexible_width: false,
+            alignments: Vec::new(),
+            pad: ' ',
+            separator: ' ',
+            overflow: Overflow::Error,
+        }
+    }
+
+    pub fn write_record<'a, I>(&mut self, fields: I) -> Result<(), WriterError>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let fields = fields.into_iter().collect::<Vec<_>>();
+        let line = self.format_line(&fields)?;
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), WriterError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn format_line(&self, fields: &[&str]) -> Result<String, WriterError> {
+        if fields.len() != self.widths.len() {
+            return Err(WriterError::FieldCountMismatch(
+                self.widths.len(),
+                fields.len(),
+            ));
+        }
+        let separator = self.separator.to_string().repeat(self.separator_length);
+        let last = self.widths.len().saturating_sub(1);
+        let mut line = String::new();
+        for (index, (field, width)) in fields.iter().zip(self.widths.iter()).enumerate() {
+            if index > 0 {
+                line.push_str(&separator);
+            }
+            if self.flexible_width && index == last {
+                line.push_str(field);
+                continue;
+            }
+            let len = field.chars().count();
+            if len > *width {
+                match self.overflow {
+                    Overflow::Error => {
+                        return Err(WriterError::FieldOverflow(index, *width, len));
+                    }
+                    Overflow::Truncate => {
+                        line.extend(field.chars().take(*width));
+                    }
+                }
+            } else {
+                let padding = self.pad.to_string().repeat(width - len);
+                match self.alignments.get(index).copied().unwrap_or(Alignment::Left) {
+                    Alignment::Left => {
+                        line.push_str(field);
+                        line.push_str(&padding);
+                    }
+                    Alignment::Right => {
+                        line.push_str(&padding);
+                        line.push_str(field);
+                    }
+                }
+            }
+        }
+        Ok(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ReaderBuilder;
+    use std::io::Cursor;
+
+    fn written(writer: Writer<Vec<u8>>) -> String {
+        String::from_utf8(writer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_write_record_left_aligned() {
+        let mut writer = Writer::new(Vec::new(), vec![7, 7, 7]);
+        writer.write_record(["987", "654", "321"]).unwrap();
+        assert_eq!(written(writer), "987    654    321    \n");
+    }
+
+    #[test]
+    fn test_write_record_right_aligned() {
+        let mut writer = WriterBuilder::new(vec![5, 5])
+            .alignments(vec![Alignment::Right, Alignment::Right])
+            .build(Vec::new())
+            .unwrap();
+        writer.write_record(["12", "345"]).unwrap();
+        assert_eq!(written(writer), "   12  345\n");
+    }
+
+    #[test]
+    fn test_write_record_custom_pad_and_separator() {
+        let mut writer = WriterBuilder::new(vec![3, 3, 3])
+            .separator_length(1)
+            .separator('-')
+            .pad('0')
+            .alignments(vec![Alignment::Right; 3])
+            .build(Vec::new())
+            .unwrap();
+        writer.write_record(["1", "2", "3"]).unwrap();
+        assert_eq!(written(writer), "001-002-003\n");
+    }
+
+    #[test]
+    fn test_write_record_flexible_width() {
+        let mut writer = WriterBuilder::new(vec![3, 3, 3])
+            .flexible_width(true)
+            .build(Vec::new())
+            .unwrap();
+        writer.write_record(["123", "456", "789abc"]).unwrap();
+        assert_eq!(written(writer), "123456789abc\n");
+    }
+
+    #[test]
+    fn test_write_record_header() {
+        let mut writer = WriterBuilder::new(vec![7, 7, 7])
+            .header(vec!["h1".into(), "h2".into(), "h3".into()])
+            .build(Vec::new())
+            .unwrap();
+        writer.write_record(["987", "654", "321"]).unwrap();
+        assert_eq!(written(writer), "h1     h2     h3     \n987    654    321    \n");
+    }
+
+    #[test]
+    fn test_write_record_overflow_error() {
+        let mut writer = Writer::new(Vec::new(), vec![3, 3, 3]);
+        let err = writer.write_record(["1234", "5", "6"]).unwrap_err();
+        assert!(matches!(err, WriterError::FieldOverflow(0, 3, 4)));
+    }
+
+    #[test]
+    fn test_write_record_overflow_truncate() {
+        let mut writer = WriterBuilder::new(vec![3, 3, 3])
+            .overflow(Overflow::Truncate)
+            .build(Vec::new())
+            .unwrap();
+        writer.write_record(["1234", "567", "8"]).unwrap();
+        assert_eq!(written(writer), "1235678  \n");
+    }
+
+    #[test]
+    fn test_write_record_field_count_mismatch() {
+        let mut writer = Writer::new(Vec::new(), vec![3, 3, 3]);
+        let err = writer.write_record(["1", "2"]).unwrap_err();
+        assert!(matches!(err, WriterError::FieldCountMismatch(3, 2)));
+    }
+
+    #[test]
+    fn test_round_trip_reader_to_writer() {
+        let data = "987    654    321    \n123    456    789    \n";
+        let reader = ReaderBuilder::new(vec![7, 7, 7])
+            .build(Cursor::new(data))
+            .unwrap();
+        let mut writer = Writer::new(Vec::new(), vec![7, 7, 7]);
+        for record in reader.records() {
+            let record = record.unwrap();
+            writer.write_record(record.iter()).unwrap();
+        }
+        assert_eq!(written(writer), data);
+    }
+
+    #[test]
+    fn test_round_trip_flexible_with_separator() {
+        let data = "ab -cd -efghij\nxy -zw -klm\n";
+        let reader = ReaderBuilder::new(vec![3, 3, 3])
+            .separator_length(1)
+            .flexible_width(true)
+            .build(Cursor::new(data))
+            .unwrap();
+        let mut writer = WriterBuilder::new(vec![3, 3, 3])
+            .separator_length(1)
+            .separator('-')
+            .flexible_width(true)
+            .build(Vec::new())
+            .unwrap();
+        for record in reader.records() {
+            let record = record.unwrap();
+            writer.write_record(record.iter()).unwrap();
+        }
+        assert_eq!(written(writer), data);
+    }
+}